@@ -18,12 +18,16 @@
 use crate::boot_time;
 use crate::boot_time::BootTime;
 use crate::metrics::log_handshake_event_stats;
+use libc::c_void;
 use log::{debug, info, warn};
 use quiche::h3;
-use std::collections::HashMap;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
 use std::default::Default;
 use std::future;
 use std::io;
+use std::net::SocketAddr;
+use std::os::unix::io::{AsRawFd, RawFd};
 use std::time::Instant;
 use thiserror::Error;
 use tokio::net::UdpSocket;
@@ -32,6 +36,55 @@ use tokio::sync::{mpsc, oneshot, watch};
 
 use super::Status;
 
+// <linux/udp.h> UDP-level socket options. Not yet exposed by the libc crate
+// on all targets we build for, so the values are hard-coded here.
+const UDP_SEGMENT: libc::c_int = 103;
+const UDP_GRO: libc::c_int = 104;
+
+// Upper bound on how many datagrams we coalesce into a single GSO/sendmmsg
+// syscall, so one flush_tx pass can't grow an unbounded batch.
+const MAX_GSO_SEGMENTS: usize = 64;
+
+// HTTP/3 error code (RFC 9114) used when we cancel a stream ourselves
+// because its request's deadline passed.
+const H3_REQUEST_CANCELLED: u64 = 0x10c;
+
+// Stop pulling new requests off request_rx once this many are already
+// waiting for stream capacity, so a congested connection applies
+// backpressure instead of buffering without bound.
+const BUFFERED_REQUESTS_HIGH_WATER_MARK: usize = 64;
+
+// RFC 9218 default urgency, used when a `Request` doesn't specify one.
+const DEFAULT_URGENCY: u8 = 3;
+
+/// Renders an RFC 9218 `Priority` structured-field value, e.g. "u=2, i".
+fn priority_field_value(urgency: u8, incremental: bool) -> String {
+    let urgency = urgency.min(7);
+    if incremental {
+        format!("u={}, i", urgency)
+    } else {
+        format!("u={}", urgency)
+    }
+}
+
+/// Parses an RFC 9218 `Priority` structured-field value. Unknown parameters
+/// are ignored; malformed urgencies fall back to the default.
+fn parse_priority_field(field: &str) -> (u8, bool) {
+    let mut urgency = DEFAULT_URGENCY;
+    let mut incremental = false;
+    for token in field.split(',') {
+        let token = token.trim();
+        if let Some(value) = token.strip_prefix("u=") {
+            if let Ok(parsed) = value.parse::<u8>() {
+                urgency = parsed.min(7);
+            }
+        } else if token == "i" {
+            incremental = true;
+        }
+    }
+    (urgency, incremental)
+}
+
 #[derive(Copy, Clone, Debug)]
 pub enum Cause {
     Probe,
@@ -39,6 +92,27 @@ pub enum Cause {
     Retry,
 }
 
+/// How Encrypted Client Hello was configured for this connection attempt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EchMode {
+    /// No ECH config was available; ECH wasn't offered.
+    Off,
+    /// A real ECH config list was offered.
+    Real,
+    /// No ECH config was available, so a GREASE extension was sent in its
+    /// place to keep ECH users and non-users indistinguishable on the wire.
+    Grease,
+}
+
+/// Outcome of ECH for a connection, as observed after the handshake.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum HandshakeEch {
+    NotOffered,
+    Accepted,
+    Rejected,
+    Greased,
+}
+
 #[derive(Clone)]
 #[allow(dead_code)]
 pub enum HandshakeResult {
@@ -59,6 +133,11 @@ pub struct HandshakeInfo {
     pub network_type: u32,
     pub private_dns_mode: u32,
     pub session_hit_checker: bool,
+    /// Whether at least one request was sent as 0-RTT early data on this
+    /// connection, regardless of whether the server went on to accept it.
+    pub early_data_used: bool,
+    /// Outcome of Encrypted Client Hello for this connection.
+    pub ech: HandshakeEch,
 }
 
 impl std::fmt::Display for HandshakeInfo {
@@ -66,12 +145,14 @@ impl std::fmt::Display for HandshakeInfo {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(
             f,
-            "cause={:?}, sent_bytes={}, recv_bytes={}, quic_version={}, session_hit_checker={}",
+            "cause={:?}, sent_bytes={}, recv_bytes={}, quic_version={}, session_hit_checker={}, early_data_used={}, ech={:?}",
             self.cause,
             self.sent_bytes,
             self.recv_bytes,
             self.quic_version,
-            self.session_hit_checker
+            self.session_hit_checker,
+            self.early_data_used,
+            self.ech
         )
     }
 }
@@ -99,6 +180,16 @@ pub struct Request {
     pub headers: Vec<h3::Header>,
     /// Expiry time for the request, relative to `CLOCK_BOOTTIME`
     pub expiry: Option<BootTime>,
+    /// Whether this request may be sent as 0-RTT early data on a resumed
+    /// connection. Only safe for idempotent operations (DNS lookups qualify);
+    /// callers must opt in explicitly.
+    pub early_data_ok: bool,
+    /// RFC 9218 urgency (0-7, lower is sent first); `None` uses the default
+    /// urgency.
+    pub urgency: Option<u8>,
+    /// RFC 9218 incremental flag: whether the response may be processed as
+    /// it arrives rather than only once complete.
+    pub incremental: bool,
     /// Channel to send the response to
     pub response_tx: oneshot::Sender<Stream>,
 }
@@ -123,6 +214,316 @@ impl Stream {
 
 const MAX_UDP_PACKET_SIZE: usize = 65536;
 
+/// Accumulates consecutive packets emitted by `quiche_conn.send()` that share
+/// a destination and pacing time, so they can be handed to the kernel as a
+/// single UDP_SEGMENT/GSO (or sendmmsg) syscall instead of one send_to() each.
+#[derive(Default)]
+struct TxBatch {
+    data: Vec<u8>,
+    to: Option<SocketAddr>,
+    at: Option<Instant>,
+    // Size of every packet in the batch except possibly the last, which may
+    // be shorter; this mirrors UDP_SEGMENT semantics.
+    segment_size: usize,
+}
+
+impl TxBatch {
+    /// Whether a packet of `len` bytes, due to `to` at pacing time `at`, can
+    /// be appended to this batch without violating GSO's equal-size-segments
+    /// (bar the last) rule.
+    fn fits(&self, to: SocketAddr, at: Instant, len: usize) -> bool {
+        match (self.to, self.at) {
+            (Some(batch_to), Some(batch_at)) => {
+                batch_to == to
+                    && batch_at == at
+                    && len <= self.segment_size
+                    && self.data.len() % self.segment_size == 0
+            }
+            _ => true,
+        }
+    }
+
+    fn push(&mut self, packet: &[u8], to: SocketAddr, at: Instant) {
+        if self.data.is_empty() {
+            self.segment_size = packet.len();
+            self.to = Some(to);
+            self.at = Some(at);
+        }
+        self.data.extend_from_slice(packet);
+    }
+
+    fn segment_count(&self) -> usize {
+        if self.segment_size == 0 {
+            0
+        } else {
+            self.data.chunks(self.segment_size).count()
+        }
+    }
+
+    fn clear(&mut self) {
+        self.data.clear();
+        self.to = None;
+        self.at = None;
+        self.segment_size = 0;
+    }
+}
+
+fn socketaddr_to_storage(addr: SocketAddr) -> (libc::sockaddr_storage, libc::socklen_t) {
+    let mut storage: libc::sockaddr_storage = unsafe { std::mem::zeroed() };
+    let len = match addr {
+        SocketAddr::V4(v4) => {
+            let raw = libc::sockaddr_in {
+                sin_family: libc::AF_INET as libc::sa_family_t,
+                sin_port: v4.port().to_be(),
+                sin_addr: libc::in_addr { s_addr: u32::from_ne_bytes(v4.ip().octets()) },
+                sin_zero: [0; 8],
+            };
+            unsafe { std::ptr::write(&mut storage as *mut _ as *mut libc::sockaddr_in, raw) };
+            std::mem::size_of::<libc::sockaddr_in>()
+        }
+        SocketAddr::V6(v6) => {
+            let raw = libc::sockaddr_in6 {
+                sin6_family: libc::AF_INET6 as libc::sa_family_t,
+                sin6_port: v6.port().to_be(),
+                sin6_flowinfo: v6.flowinfo(),
+                sin6_addr: libc::in6_addr { s6_addr: v6.ip().octets() },
+                sin6_scope_id: v6.scope_id(),
+            };
+            unsafe { std::ptr::write(&mut storage as *mut _ as *mut libc::sockaddr_in6, raw) };
+            std::mem::size_of::<libc::sockaddr_in6>()
+        }
+    };
+    (storage, len as libc::socklen_t)
+}
+
+fn storage_to_socketaddr(storage: &libc::sockaddr_storage) -> io::Result<SocketAddr> {
+    match storage.ss_family as i32 {
+        libc::AF_INET => {
+            let addr: libc::sockaddr_in =
+                unsafe { std::ptr::read(storage as *const _ as *const libc::sockaddr_in) };
+            let ip = std::net::Ipv4Addr::from(addr.sin_addr.s_addr.to_ne_bytes());
+            Ok(SocketAddr::new(ip.into(), u16::from_be(addr.sin_port)))
+        }
+        libc::AF_INET6 => {
+            let addr: libc::sockaddr_in6 =
+                unsafe { std::ptr::read(storage as *const _ as *const libc::sockaddr_in6) };
+            let ip = std::net::Ipv6Addr::from(addr.sin6_addr.s6_addr);
+            Ok(SocketAddr::new(ip.into(), u16::from_be(addr.sin6_port)))
+        }
+        family => {
+            Err(io::Error::new(io::ErrorKind::InvalidInput, format!("unsupported af {}", family)))
+        }
+    }
+}
+
+/// Sends `data` as one datagram split into `segment_size`-byte segments by
+/// the kernel/NIC (UDP_SEGMENT cmsg), with a possibly-shorter final segment.
+unsafe fn send_gso_once(
+    fd: RawFd,
+    data: &[u8],
+    to: SocketAddr,
+    segment_size: u16,
+) -> io::Result<usize> {
+    let (mut addr, addr_len) = socketaddr_to_storage(to);
+    let mut iov = libc::iovec { iov_base: data.as_ptr() as *mut c_void, iov_len: data.len() };
+    let mut cmsg_buf = vec![0u8; libc::CMSG_SPACE(std::mem::size_of::<u16>() as u32) as usize];
+
+    let mut msg: libc::msghdr = std::mem::zeroed();
+    msg.msg_name = &mut addr as *mut _ as *mut c_void;
+    msg.msg_namelen = addr_len;
+    msg.msg_iov = &mut iov;
+    msg.msg_iovlen = 1;
+    msg.msg_control = cmsg_buf.as_mut_ptr() as *mut c_void;
+    msg.msg_controllen = cmsg_buf.len() as _;
+
+    let cmsg = libc::CMSG_FIRSTHDR(&msg);
+    (*cmsg).cmsg_level = libc::SOL_UDP;
+    (*cmsg).cmsg_type = UDP_SEGMENT;
+    (*cmsg).cmsg_len = libc::CMSG_LEN(std::mem::size_of::<u16>() as u32) as _;
+    std::ptr::write(libc::CMSG_DATA(cmsg) as *mut u16, segment_size);
+
+    let ret = libc::sendmsg(fd, &msg, 0);
+    if ret < 0 {
+        Err(io::Error::last_os_error())
+    } else {
+        Ok(ret as usize)
+    }
+}
+
+/// Sends each of `chunks` to `to` as its own datagram via a single sendmmsg
+/// syscall. Returns how many of `chunks` were accepted by the kernel.
+unsafe fn send_mmsg_once(fd: RawFd, chunks: &[&[u8]], to: SocketAddr) -> io::Result<usize> {
+    let (mut addr, addr_len) = socketaddr_to_storage(to);
+    let mut iovecs: Vec<libc::iovec> = chunks
+        .iter()
+        .map(|c| libc::iovec { iov_base: c.as_ptr() as *mut c_void, iov_len: c.len() })
+        .collect();
+    let mut msgs: Vec<libc::mmsghdr> = iovecs
+        .iter_mut()
+        .map(|iov| libc::mmsghdr {
+            msg_hdr: libc::msghdr {
+                msg_name: &mut addr as *mut _ as *mut c_void,
+                msg_namelen: addr_len,
+                msg_iov: iov,
+                msg_iovlen: 1,
+                msg_control: std::ptr::null_mut(),
+                msg_controllen: 0,
+                msg_flags: 0,
+            },
+            msg_len: 0,
+        })
+        .collect();
+    let sent = libc::sendmmsg(fd, msgs.as_mut_ptr(), msgs.len() as u32, 0);
+    if sent < 0 {
+        Err(io::Error::last_os_error())
+    } else {
+        Ok(sent as usize)
+    }
+}
+
+async fn send_gso(
+    socket: &UdpSocket,
+    data: &[u8],
+    to: SocketAddr,
+    segment_size: u16,
+) -> io::Result<usize> {
+    loop {
+        socket.writable().await?;
+        match unsafe { send_gso_once(socket.as_raw_fd(), data, to, segment_size) } {
+            Err(e) if e.kind() == io::ErrorKind::WouldBlock => continue,
+            other => return other,
+        }
+    }
+}
+
+async fn send_mmsg(socket: &UdpSocket, chunks: &[&[u8]], to: SocketAddr) -> io::Result<usize> {
+    loop {
+        socket.writable().await?;
+        match unsafe { send_mmsg_once(socket.as_raw_fd(), chunks, to) } {
+            Err(e) if e.kind() == io::ErrorKind::WouldBlock => continue,
+            other => return other,
+        }
+    }
+}
+
+/// Transmits and clears `batch`, preferring GSO, falling back to sendmmsg,
+/// and finally to one send_to() per packet if neither is available.
+async fn send_batch(socket: &UdpSocket, batch: &mut TxBatch) -> io::Result<()> {
+    if batch.data.is_empty() {
+        return Ok(());
+    }
+    let to = batch.to.expect("non-empty batch always has a destination");
+
+    if batch.segment_count() <= 1 {
+        socket.send_to(&batch.data, to).await?;
+        batch.clear();
+        return Ok(());
+    }
+
+    match send_gso(socket, &batch.data, to, batch.segment_size as u16).await {
+        Ok(_) => {
+            batch.clear();
+            return Ok(());
+        }
+        Err(e) if e.raw_os_error() == Some(libc::EINVAL) => {
+            debug!("UDP_SEGMENT unsupported, falling back to sendmmsg");
+        }
+        Err(e) => return Err(e),
+    }
+
+    let chunks: Vec<&[u8]> = batch.data.chunks(batch.segment_size).collect();
+    match send_mmsg(socket, &chunks, to).await {
+        Ok(sent) if sent == chunks.len() => {
+            batch.clear();
+            return Ok(());
+        }
+        // sendmmsg(2) can legitimately send a prefix of the batch before hitting an error;
+        // only resend the chunks it didn't already accept, to avoid duplicating datagrams
+        // on the wire.
+        Ok(sent) => {
+            debug!("sendmmsg sent {}/{}, falling back to per-packet send_to for the rest", sent, chunks.len());
+            for chunk in &chunks[sent..] {
+                socket.send_to(chunk, to).await?;
+            }
+        }
+        Err(_) => {
+            debug!("sendmmsg unavailable, falling back to per-packet send_to");
+            for chunk in &chunks {
+                socket.send_to(chunk, to).await?;
+            }
+        }
+    }
+    batch.clear();
+    Ok(())
+}
+
+/// Receives one (possibly GRO-coalesced) datagram into `buf`, returning the
+/// total bytes received, the sender, and the size of each logical segment
+/// within it (equal to the bytes received if GRO didn't coalesce anything).
+async fn recv_gro(socket: &UdpSocket, buf: &mut [u8]) -> io::Result<(usize, SocketAddr, usize)> {
+    loop {
+        socket.readable().await?;
+        match unsafe { recv_gro_once(socket.as_raw_fd(), buf) } {
+            Err(e) if e.kind() == io::ErrorKind::WouldBlock => continue,
+            other => return other,
+        }
+    }
+}
+
+unsafe fn recv_gro_once(fd: RawFd, buf: &mut [u8]) -> io::Result<(usize, SocketAddr, usize)> {
+    let mut storage: libc::sockaddr_storage = std::mem::zeroed();
+    let mut iov = libc::iovec { iov_base: buf.as_mut_ptr() as *mut c_void, iov_len: buf.len() };
+    // Large enough for a UDP_GRO cmsg (one u16, cmsg-aligned).
+    let mut cmsg_buf = [0u8; 64];
+
+    let mut msg: libc::msghdr = std::mem::zeroed();
+    msg.msg_name = &mut storage as *mut _ as *mut c_void;
+    msg.msg_namelen = std::mem::size_of::<libc::sockaddr_storage>() as libc::socklen_t;
+    msg.msg_iov = &mut iov;
+    msg.msg_iovlen = 1;
+    msg.msg_control = cmsg_buf.as_mut_ptr() as *mut c_void;
+    msg.msg_controllen = cmsg_buf.len() as _;
+
+    let n = libc::recvmsg(fd, &mut msg, 0);
+    if n < 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    let from = storage_to_socketaddr(&storage)?;
+    let mut segment_size = n as usize;
+    let mut cmsg = libc::CMSG_FIRSTHDR(&msg);
+    while !cmsg.is_null() {
+        let hdr = &*cmsg;
+        if hdr.cmsg_level == libc::SOL_UDP && hdr.cmsg_type == UDP_GRO {
+            let mut raw = [0u8; 2];
+            std::ptr::copy_nonoverlapping(libc::CMSG_DATA(cmsg), raw.as_mut_ptr(), 2);
+            segment_size = u16::from_ne_bytes(raw) as usize;
+        }
+        cmsg = libc::CMSG_NXTHDR(&msg, cmsg);
+    }
+
+    Ok((n as usize, from, segment_size))
+}
+
+/// Best-effort enables UDP_GRO on `socket`; silently leaves GRO off on
+/// kernels that don't support it, since recv_gro() tolerates un-coalesced
+/// datagrams just fine.
+fn enable_udp_gro(socket: &UdpSocket) {
+    let one: libc::c_int = 1;
+    let ret = unsafe {
+        libc::setsockopt(
+            socket.as_raw_fd(),
+            libc::SOL_UDP,
+            UDP_GRO,
+            &one as *const _ as *const c_void,
+            std::mem::size_of::<libc::c_int>() as libc::socklen_t,
+        )
+    };
+    if ret < 0 {
+        debug!("UDP_GRO not supported: {}", io::Error::last_os_error());
+    }
+}
+
 struct Driver {
     request_rx: mpsc::Receiver<Request>,
     status_tx: watch::Sender<Status>,
@@ -141,17 +542,48 @@ struct Driver {
     closing: bool,
     handshake_info: HandshakeInfo,
     connection_start: Instant,
+    ech_mode: EchMode,
+    // Whether this connection attempt is itself a retry made in response to
+    // an earlier ECH rejection, so we don't chase retry configs forever.
+    ech_is_retry: bool,
 }
 
 struct H3Driver {
     driver: Driver,
     // h3_conn sometimes can't "fit" a request in its available windows.
-    // This value holds a peeked request in that case, waiting for
-    // transmission to become possible.
-    buffered_request: Option<Request>,
+    // Requests queue up here in that case, waiting for transmission to
+    // become possible; the front of the queue is retried first.
+    buffered_requests: VecDeque<Request>,
     h3_conn: h3::Connection,
     requests: HashMap<u64, Request>,
     streams: HashMap<u64, Stream>,
+    // Stream IDs for requests sent while the connection was still in early
+    // data. Drained once we learn whether the server accepted 0-RTT.
+    early_data_streams: HashSet<u64>,
+    // Set once we've checked whether early data was accepted/rejected, so we
+    // don't re-check (and potentially re-issue requests twice) every pass.
+    early_data_resolved: bool,
+    // Min-heap of (expiry, stream_id) for every in-flight request that has a
+    // deadline, so `drive_once` can cancel whichever one expires first.
+    // Entries for streams that already completed via `respond()` are simply
+    // skipped when popped, rather than proactively removed.
+    deadlines: BinaryHeap<Reverse<(BootTime, u64)>>,
+}
+
+/// Resolves once the earliest deadline in `deadlines` passes, yielding the
+/// stream ID it belongs to; that stream may already have completed. Pending
+/// forever if there are none.
+async fn next_deadline(deadlines: &BinaryHeap<Reverse<(BootTime, u64)>>) -> u64 {
+    match deadlines.peek() {
+        Some(Reverse((expiry, stream_id))) => {
+            let now = BootTime::now();
+            if *expiry > now {
+                boot_time::sleep(*expiry - now).await;
+            }
+            *stream_id
+        }
+        None => future::pending().await,
+    }
 }
 
 async fn optional_timeout(timeout: Option<boot_time::Duration>, net_id: u32) {
@@ -164,6 +596,7 @@ async fn optional_timeout(timeout: Option<boot_time::Duration>, net_id: u32) {
 
 /// Creates a future which when polled will handle events related to a HTTP/3 connection.
 /// The returned error code will explain why the connection terminated.
+#[allow(clippy::too_many_arguments)]
 pub async fn drive(
     request_rx: mpsc::Receiver<Request>,
     status_tx: watch::Sender<Status>,
@@ -171,11 +604,25 @@ pub async fn drive(
     socket: UdpSocket,
     net_id: u32,
     handshake_info: HandshakeInfo,
+    ech_mode: EchMode,
+    ech_is_retry: bool,
 ) -> Result<()> {
-    Driver::new(request_rx, status_tx, quiche_conn, socket, net_id, handshake_info).drive().await
+    Driver::new(
+        request_rx,
+        status_tx,
+        quiche_conn,
+        socket,
+        net_id,
+        handshake_info,
+        ech_mode,
+        ech_is_retry,
+    )
+    .drive()
+    .await
 }
 
 impl Driver {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         request_rx: mpsc::Receiver<Request>,
         status_tx: watch::Sender<Status>,
@@ -183,7 +630,10 @@ impl Driver {
         socket: UdpSocket,
         net_id: u32,
         handshake_info: HandshakeInfo,
+        ech_mode: EchMode,
+        ech_is_retry: bool,
     ) -> Self {
+        enable_udp_gro(&socket);
         Self {
             request_rx,
             status_tx,
@@ -194,6 +644,8 @@ impl Driver {
             closing: false,
             handshake_info,
             connection_start: Instant::now(),
+            ech_mode,
+            ech_is_retry,
         }
     }
 
@@ -206,7 +658,30 @@ impl Driver {
         }
     }
 
-    fn handle_closed(&self) -> Result<()> {
+    /// Sends `Status::Dead`, handing back every request that never got a
+    /// final answer so `Network` can re-dispatch them onto a fresh
+    /// connection. `extra_pending` carries requests an `H3Driver` had
+    /// in flight; anything still sitting unread in `request_rx` is picked
+    /// up here too. Already-expired requests are dropped rather than
+    /// forwarded, since retrying them would be pointless.
+    fn send_dead(&mut self, mut extra_pending: Vec<Request>, ech_retry_config: Option<Vec<u8>>) {
+        self.request_rx.close();
+        while let Ok(request) = self.request_rx.try_recv() {
+            extra_pending.push(request);
+        }
+        let now = BootTime::now();
+        extra_pending.retain(|request| request.expiry.map_or(true, |expiry| now <= expiry));
+
+        // We don't care if the receiver has hung up
+        let session = self.quiche_conn.session().map(<[_]>::to_vec);
+        let _ = self.status_tx.send(Status::Dead {
+            session,
+            pending: extra_pending,
+            ech_retry_config,
+        });
+    }
+
+    fn handle_closed(&mut self, pending: Vec<Request>) -> Result<()> {
         if self.quiche_conn.is_closed() {
             // TODO: Also log local_error() once Quiche 0.10.0 is available.
             info!(
@@ -215,16 +690,20 @@ impl Driver {
                 self.net_id,
                 self.quiche_conn.peer_error()
             );
-            // We don't care if the receiver has hung up
-            let session = self.quiche_conn.session().map(<[_]>::to_vec);
-            let _ = self.status_tx.send(Status::Dead { session });
+            // If draining already sent Status::Dead with the real pending list, don't
+            // overwrite it here: `pending` is empty by this point (it was drained then),
+            // and status_tx only retains the latest value.
+            if !matches!(*self.status_tx.borrow(), Status::Dead { .. }) {
+                self.closing = true;
+                self.send_dead(pending, None);
+            }
             Err(Error::Closed)
         } else {
             Ok(())
         }
     }
 
-    fn handle_draining(&mut self) {
+    fn handle_draining(&mut self, pending: Vec<Request>) {
         if self.quiche_conn.is_draining() && !self.closing {
             // TODO: Also log local_error() once Quiche 0.10.0 is available.
             info!(
@@ -233,18 +712,8 @@ impl Driver {
                 self.net_id,
                 self.quiche_conn.peer_error()
             );
-            // We don't care if the receiver has hung up
-            let session = self.quiche_conn.session().map(<[_]>::to_vec);
-            let _ = self.status_tx.send(Status::Dead { session });
-
-            self.request_rx.close();
-            // Drain the pending DNS requests from the queue to make their corresponding future
-            // tasks return some error quickly rather than timeout. However, the DNS requests
-            // that has been sent will still time out.
-            // TODO: re-issue the outstanding DNS requests, such as passing H3Driver.requests
-            // along with Status::Dead to the `Network` that can re-issue the DNS requests.
-            while self.request_rx.try_recv().is_ok() {}
             self.closing = true;
+            self.send_dead(pending, None);
         }
     }
 
@@ -262,6 +731,32 @@ impl Driver {
             self.handshake_info.sent_bytes = self.quiche_conn.stats().sent_bytes;
             self.handshake_info.recv_bytes = self.quiche_conn.stats().recv_bytes;
             self.handshake_info.quic_version = quiche::PROTOCOL_VERSION;
+
+            // ECH's outcome is only known once the full (1-RTT) handshake completes.
+            if self.quiche_conn.is_established() {
+                self.handshake_info.ech = match self.ech_mode {
+                    EchMode::Off => HandshakeEch::NotOffered,
+                    EchMode::Grease => HandshakeEch::Greased,
+                    EchMode::Real if self.quiche_conn.ech_accepted() => HandshakeEch::Accepted,
+                    EchMode::Real => HandshakeEch::Rejected,
+                };
+                if self.handshake_info.ech == HandshakeEch::Rejected && !self.ech_is_retry {
+                    if let Some(retry_config) = self.quiche_conn.ech_retry_config() {
+                        warn!(
+                            "ECH rejected on network {}, retrying once with updated config",
+                            self.net_id
+                        );
+                        log_handshake_event_stats(HandshakeResult::TlsFail, self.handshake_info);
+                        let _ = self.quiche_conn.close(true, 0, b"ECH_RETRY");
+                        // Flush so the CONNECTION_CLOSE frame quiche just queued actually
+                        // reaches the peer, instead of waiting out its idle timeout.
+                        self.flush_tx().await?;
+                        self.send_dead(Vec::new(), Some(retry_config.to_vec()));
+                        return Err(Error::Closed);
+                    }
+                }
+            }
+
             log_handshake_event_stats(HandshakeResult::Success, self.handshake_info);
             let h3_config = h3::Config::new()?;
             let h3_conn = h3::Connection::with_transport(&mut self.quiche_conn, &h3_config)?;
@@ -290,10 +785,15 @@ impl Driver {
                 }
             }
             // If we got packets from our peer, pass them to quiche
-            Ok((size, from)) = self.socket.recv_from(self.buffer.as_mut()) => {
+            Ok((size, from, segment_size)) = recv_gro(&self.socket, self.buffer.as_mut()) => {
                 let local = self.socket.local_addr()?;
-                self.quiche_conn.recv(&mut self.buffer[..size], quiche::RecvInfo { from, to: local })?;
-                debug!("Received {} bytes on network {}", size, self.net_id);
+                let mut offset = 0;
+                while offset < size {
+                    let end = (offset + segment_size).min(size);
+                    self.quiche_conn.recv(&mut self.buffer[offset..end], quiche::RecvInfo { from, to: local })?;
+                    offset = end;
+                }
+                debug!("Received {} bytes (segment size {}) on network {}", size, segment_size, self.net_id);
             }
         };
 
@@ -304,26 +804,40 @@ impl Driver {
         // tell the status watcher not to use the connection. Besides, per Quiche document,
         // the connection should not be dropped until is_closed() returns true.
         // This tokio task will become unowned and get dropped when is_closed() returns true.
-        self.handle_draining();
+        self.handle_draining(Vec::new());
 
         // If the connection has closed, tear down
-        self.handle_closed()?;
+        self.handle_closed(Vec::new())?;
 
         Ok(self)
     }
 
     async fn flush_tx(&mut self) -> Result<()> {
-        let send_buf = self.buffer.as_mut();
+        // Coalesce consecutive packets bound for the same peer at the same
+        // pacing time into one GSO/sendmmsg batch instead of one syscall per
+        // packet.
+        let mut batch = TxBatch::default();
         loop {
-            match self.quiche_conn.send(send_buf) {
-                Err(quiche::Error::Done) => return Ok(()),
-                Err(e) => return Err(e.into()),
+            match self.quiche_conn.send(self.buffer.as_mut()) {
+                Err(quiche::Error::Done) => break,
+                Err(e) => {
+                    send_batch(&self.socket, &mut batch).await?;
+                    return Err(e.into());
+                }
                 Ok((valid_len, send_info)) => {
-                    self.socket.send_to(&send_buf[..valid_len], send_info.to).await?;
-                    debug!("Sent {} bytes on network {}", valid_len, self.net_id);
+                    if !batch.fits(send_info.to, send_info.at, valid_len) {
+                        send_batch(&self.socket, &mut batch).await?;
+                    }
+                    batch.push(&self.buffer[..valid_len], send_info.to, send_info.at);
+                    if batch.segment_count() >= MAX_GSO_SEGMENTS {
+                        send_batch(&self.socket, &mut batch).await?;
+                    }
                 }
             }
         }
+        send_batch(&self.socket, &mut batch).await?;
+        debug!("Flushed tx batch on network {}", self.net_id);
+        Ok(())
     }
 }
 
@@ -334,7 +848,39 @@ impl H3Driver {
             h3_conn,
             requests: HashMap::new(),
             streams: HashMap::new(),
-            buffered_request: None,
+            buffered_requests: VecDeque::new(),
+            early_data_streams: HashSet::new(),
+            early_data_resolved: false,
+            deadlines: BinaryHeap::new(),
+        }
+    }
+
+    /// Cancels an in-flight stream whose deadline has passed, returning
+    /// capacity to the connection and delivering a timeout-flavored response
+    /// to the caller. A no-op if the stream already completed normally.
+    fn cancel_stream(&mut self, stream_id: u64) {
+        if let Some(request) = self.requests.remove(&stream_id) {
+            info!(
+                "Deadline exceeded for stream {} on network {}",
+                stream_id, self.driver.net_id
+            );
+            let _ = self.driver.quiche_conn.stream_shutdown(
+                stream_id,
+                quiche::Shutdown::Read,
+                H3_REQUEST_CANCELLED,
+            );
+            let _ = self.driver.quiche_conn.stream_shutdown(
+                stream_id,
+                quiche::Shutdown::Write,
+                H3_REQUEST_CANCELLED,
+            );
+            self.streams.remove(&stream_id);
+            self.early_data_streams.remove(&stream_id);
+            let _ = request.response_tx.send(Stream {
+                headers: Vec::new(),
+                data: Vec::new(),
+                error: Some(H3_REQUEST_CANCELLED),
+            });
         }
     }
 
@@ -342,8 +888,13 @@ impl H3Driver {
         let _ = self.driver.status_tx.send(Status::H3);
         loop {
             if let Err(e) = self.drive_once().await {
-                let session = self.driver.quiche_conn.session().map(<[_]>::to_vec);
-                let _ = self.driver.status_tx.send(Status::Dead { session });
+                // Error::Closed means handle_closed() already sent Status::Dead with the real
+                // pending list; sending again here with a now-empty take_pending() would
+                // clobber it, since status_tx only retains the latest value.
+                if !matches!(e, Error::Closed) {
+                    let pending = self.take_pending();
+                    self.driver.send_dead(pending, None);
+                }
                 return Err(e);
             }
         }
@@ -353,17 +904,21 @@ impl H3Driver {
         // We can't call self.driver.drive_once at the same time as
         // self.driver.request_rx.recv() due to ownership
         let timer = optional_timeout(self.driver.quiche_conn.timeout(), self.driver.net_id);
-        // If we've buffered a request (due to the connection being full)
-        // try to resend that first
-        if let Some(request) = self.buffered_request.take() {
-            self.handle_request(request)?;
+        // Retry buffered requests from the front of the queue while the connection still has
+        // capacity for them, stopping at the first one that's still blocked.
+        while let Some(request) = self.buffered_requests.pop_front() {
+            if let Some(request) = self.try_send_request(request)? {
+                self.buffered_requests.push_front(request);
+                break;
+            }
+        }
+        if !self.buffered_requests.is_empty() {
             self.driver.flush_tx().await?;
         }
         select! {
-            // Only attempt to enqueue new requests if we have no buffered request and aren't
-            // closing. Maybe limit the number of in-flight queries if the handshake
-            // still hasn't finished.
-            msg = self.driver.request_rx.recv(), if !self.driver.closing && self.buffered_request.is_none() => {
+            // Only pull new requests off the channel if we're not closing and haven't hit the
+            // high-water mark of requests already waiting for stream capacity.
+            msg = self.driver.request_rx.recv(), if !self.driver.closing && self.buffered_requests.len() < BUFFERED_REQUESTS_HIGH_WATER_MARK => {
                 match msg {
                     Some(request) => self.handle_request(request)?,
                     None => self.shutdown(true, b"DONE").await?,
@@ -375,11 +930,21 @@ impl H3Driver {
                 self.driver.quiche_conn.on_timeout()
             }
             // If we got packets from our peer, pass them to quiche
-            Ok((size, from)) = self.driver.socket.recv_from(self.driver.buffer.as_mut()) => {
+            Ok((size, from, segment_size)) = recv_gro(&self.driver.socket, self.driver.buffer.as_mut()) => {
                 let local = self.driver.socket.local_addr()?;
-                self.driver.quiche_conn.recv(&mut self.driver.buffer[..size], quiche::RecvInfo { from, to: local }).map(|_| ())?;
-
-                debug!("Received {} bytes on network {}", size, self.driver.net_id);
+                let mut offset = 0;
+                while offset < size {
+                    let end = (offset + segment_size).min(size);
+                    self.driver.quiche_conn.recv(&mut self.driver.buffer[offset..end], quiche::RecvInfo { from, to: local }).map(|_| ())?;
+                    offset = end;
+                }
+                debug!("Received {} bytes (segment size {}) on network {}", size, segment_size, self.driver.net_id);
+            }
+            // If a request's deadline passes before it's answered, cancel its stream so the
+            // caller learns promptly and the stream's resources are freed for buffered requests.
+            stream_id = next_deadline(&self.deadlines) => {
+                self.deadlines.pop();
+                self.cancel_stream(stream_id);
             }
         };
 
@@ -389,14 +954,40 @@ impl H3Driver {
         // Process any incoming HTTP/3 events
         self.flush_h3().await?;
 
+        // Now that we may have left early data, learn whether the server
+        // accepted or rejected it and re-issue anything it rejected.
+        self.check_early_data()?;
+
+        // If the connection has closed, tear down, handing back everything
+        // still unanswered. Checked ahead of draining so a connection that
+        // reaches both states in the same pass only reports pending requests
+        // once.
+        if self.driver.quiche_conn.is_closed() {
+            let pending = self.take_pending();
+            return self.driver.handle_closed(pending);
+        }
+
         // If the connection has entered draining state (the server is closing the connection),
-        // tell the status watcher not to use the connection. Besides, per Quiche document,
-        // the connection should not be dropped until is_closed() returns true.
-        // This tokio task will become unowned and get dropped when is_closed() returns true.
-        self.driver.handle_draining();
+        // tell the status watcher not to use the connection, handing back everything still
+        // unanswered so it can be retried on a fresh connection. Per Quiche document, the
+        // connection should not be dropped until is_closed() returns true. This tokio task will
+        // become unowned and get dropped when is_closed() returns true.
+        if self.driver.quiche_conn.is_draining() && !self.driver.closing {
+            let pending = self.take_pending();
+            self.driver.handle_draining(pending);
+        }
+        Ok(())
+    }
 
-        // If the connection has closed, tear down
-        self.driver.handle_closed()
+    /// Takes every request that hasn't received a final answer: a buffered
+    /// request waiting for stream capacity, and any request whose stream
+    /// never produced a `Finished`/`Reset` event (those are removed from
+    /// `self.requests` by `respond()` as soon as they complete normally).
+    fn take_pending(&mut self) -> Vec<Request> {
+        let mut pending: Vec<Request> = self.buffered_requests.drain(..).collect();
+        pending.extend(self.requests.drain().map(|(_, request)| request));
+        self.streams.clear();
+        pending
     }
 
     fn handle_request(&mut self, request: Request) -> Result<()> {
@@ -409,19 +1000,43 @@ impl H3Driver {
                 return Ok(());
             }
         }
+        // Only idempotent requests may be sent before the handshake
+        // completes; everything else waits for 1-RTT.
+        if self.driver.quiche_conn.is_in_early_data() && !request.early_data_ok {
+            info!("Deferring non-idempotent request until 1-RTT, network {}", self.driver.net_id);
+            self.buffered_requests.push_back(request);
+            return Ok(());
+        }
+        if let Some(request) = self.try_send_request(request)? {
+            self.buffered_requests.push_back(request);
+        }
+        Ok(())
+    }
+
+    /// Attempts to issue `request` on the wire, returning it back if the
+    /// connection has no room for it right now (the caller should queue it
+    /// for a later retry). Re-checks the expiry and early-data-safety gates
+    /// `handle_request` applies on first attempt, since a request can sit in
+    /// `buffered_requests` across many calls before this is reached, and
+    /// either gate may now trip even though it didn't on the first attempt.
+    fn try_send_request(&mut self, request: Request) -> Result<Option<Request>> {
+        if let Some(expiry) = request.expiry {
+            if BootTime::now() > expiry {
+                warn!("Abandoning expired DNS request");
+                return Ok(None);
+            }
+        }
+        if self.driver.quiche_conn.is_in_early_data() && !request.early_data_ok {
+            return Ok(Some(request));
+        }
+        let sent_in_early_data = self.driver.quiche_conn.is_in_early_data();
         let stream_id =
             // If h3_conn says the stream is blocked, this error is recoverable just by trying
-            // again once the stream has made progress. Buffer the request for a later retry.
+            // again once the stream has made progress; hand the request back unsent.
             match self.h3_conn.send_request(&mut self.driver.quiche_conn, &request.headers, true) {
                 Err(h3::Error::StreamBlocked) | Err(h3::Error::TransportError(quiche::Error::StreamLimit)) => {
-                    // We only call handle_request on a value that has just come out of
-                    // buffered_request, or when buffered_request is empty. This assert just
-                    // validates that we don't break that assumption later, as it could result in
-                    // requests being dropped on the floor under high load.
-                    info!("Stream has become blocked, buffering one request.");
-                    assert!(self.buffered_request.is_none());
-                    self.buffered_request = Some(request);
-                    return Ok(())
+                    info!("Stream has become blocked, queuing request for later.");
+                    return Ok(Some(request));
                 }
                 result => result?,
             };
@@ -431,7 +1046,53 @@ impl H3Driver {
             self.driver.net_id,
             self.driver.quiche_conn.stream_capacity(stream_id)
         );
+        let priority = priority_field_value(request.urgency.unwrap_or(DEFAULT_URGENCY), request.incremental);
+        if let Err(e) = self.h3_conn.send_priority_update_for_request(
+            &mut self.driver.quiche_conn,
+            stream_id,
+            priority.as_bytes(),
+        ) {
+            warn!("Failed to set priority {:?} for stream {}: {:?}", priority, stream_id, e);
+        }
+        if sent_in_early_data {
+            self.early_data_streams.insert(stream_id);
+            self.driver.handshake_info.early_data_used = true;
+        }
+        if let Some(expiry) = request.expiry {
+            self.deadlines.push(Reverse((expiry, stream_id)));
+        }
         self.requests.insert(stream_id, request);
+        Ok(None)
+    }
+
+    /// Once the connection has left early data, checks whether the server
+    /// accepted the 0-RTT session resumption. If it didn't, every request we
+    /// sent during early data was silently discarded by the server, so pull
+    /// it back out and resend it now that we're on a 1-RTT connection.
+    fn check_early_data(&mut self) -> Result<()> {
+        if self.early_data_resolved || self.driver.quiche_conn.is_in_early_data() {
+            return Ok(());
+        }
+        self.early_data_resolved = true;
+        if self.early_data_streams.is_empty() {
+            return Ok(());
+        }
+        if self.driver.quiche_conn.is_resumed() {
+            debug!("0-RTT early data accepted on network {}", self.driver.net_id);
+            return Ok(());
+        }
+        let rejected: Vec<u64> = self.early_data_streams.drain().collect();
+        warn!(
+            "0-RTT early data rejected on network {}, re-issuing {} request(s)",
+            self.driver.net_id,
+            rejected.len()
+        );
+        for stream_id in rejected {
+            self.streams.remove(&stream_id);
+            if let Some(request) = self.requests.remove(&stream_id) {
+                self.handle_request(request)?;
+            }
+        }
         Ok(())
     }
 
@@ -544,14 +1205,37 @@ impl H3Driver {
                     "process_h3_event: h3::Event::PriorityUpdate on stream ID {}, network {}",
                     stream_id, self.driver.net_id
                 );
-                // It tells us that PRIORITY_UPDATE frame is received, but we are not
-                // using it in our code currently. No-op should be fine.
+                if let Ok(Some(field_value)) = self.h3_conn.take_last_priority_update(stream_id) {
+                    self.apply_priority_update(stream_id, &field_value);
+                }
             }
             h3::Event::GoAway => self.shutdown(false, b"SERVER GOAWAY").await?,
         }
         Ok(())
     }
 
+    /// Applies a server-sent RFC 9218 reprioritization to our record of the
+    /// corresponding tracked request, if it's still in flight.
+    fn apply_priority_update(&mut self, stream_id: u64, field_value: &[u8]) {
+        let field_value = match std::str::from_utf8(field_value) {
+            Ok(field_value) => field_value,
+            Err(_) => return,
+        };
+        let request = match self.requests.get_mut(&stream_id) {
+            Some(request) => request,
+            None => return,
+        };
+        let (urgency, incremental) = parse_priority_field(field_value);
+        // Only our record changes; the transport's send order for this stream
+        // is left as it was.
+        request.urgency = Some(urgency);
+        request.incremental = incremental;
+        info!(
+            "Server reprioritized stream {} to urgency={}, incremental={} on network {}",
+            stream_id, urgency, incremental, self.driver.net_id
+        );
+    }
+
     async fn shutdown(&mut self, send_goaway: bool, msg: &[u8]) -> Result<()> {
         info!(
             "Closing connection {} on network {} with msg {:?}",
@@ -586,3 +1270,85 @@ impl H3Driver {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn addr(port: u16) -> SocketAddr {
+        SocketAddr::from(([127, 0, 0, 1], port))
+    }
+
+    #[test]
+    fn tx_batch_fits_empty() {
+        let batch = TxBatch::default();
+        assert!(batch.fits(addr(1), Instant::now(), 10));
+    }
+
+    #[test]
+    fn tx_batch_fits_rejects_different_destination_or_pacing_time() {
+        let mut batch = TxBatch::default();
+        let at = Instant::now();
+        batch.push(&[0u8; 10], addr(1), at);
+        assert!(!batch.fits(addr(2), at, 10));
+        assert!(!batch.fits(addr(1), Instant::now() + std::time::Duration::from_secs(1), 10));
+    }
+
+    #[test]
+    fn tx_batch_fits_rejects_larger_or_misaligned_segment() {
+        let mut batch = TxBatch::default();
+        let at = Instant::now();
+        batch.push(&[0u8; 10], addr(1), at);
+        // Larger than the established segment size violates GSO's equal-size rule.
+        assert!(!batch.fits(addr(1), at, 11));
+        // A smaller-or-equal packet fits as long as the batch is still segment-aligned.
+        assert!(batch.fits(addr(1), at, 10));
+        batch.push(&[0u8; 5], addr(1), at);
+        assert!(!batch.fits(addr(1), at, 5));
+    }
+
+    #[test]
+    fn tx_batch_push_and_segment_count() {
+        let mut batch = TxBatch::default();
+        let at = Instant::now();
+        batch.push(&[1u8; 10], addr(1), at);
+        batch.push(&[2u8; 10], addr(1), at);
+        batch.push(&[3u8; 4], addr(1), at);
+        assert_eq!(batch.segment_count(), 3);
+        assert_eq!(batch.data.len(), 24);
+        assert_eq!(batch.to, Some(addr(1)));
+    }
+
+    #[test]
+    fn tx_batch_clear_resets_state() {
+        let mut batch = TxBatch::default();
+        batch.push(&[1u8; 10], addr(1), Instant::now());
+        batch.clear();
+        assert_eq!(batch.segment_count(), 0);
+        assert!(batch.to.is_none());
+        assert!(batch.at.is_none());
+        assert!(batch.data.is_empty());
+    }
+
+    #[test]
+    fn priority_field_round_trips() {
+        for (urgency, incremental) in [(0, false), (3, false), (7, true), (5, true)] {
+            let field = priority_field_value(urgency, incremental);
+            assert_eq!(parse_priority_field(&field), (urgency, incremental));
+        }
+    }
+
+    #[test]
+    fn priority_field_value_clamps_urgency_to_seven() {
+        assert_eq!(priority_field_value(9, false), "u=7");
+    }
+
+    #[test]
+    fn parse_priority_field_clamps_and_defaults() {
+        assert_eq!(parse_priority_field("u=9"), (7, false));
+        assert_eq!(parse_priority_field("u=2, i"), (2, true));
+        // Malformed/unknown content falls back to the default urgency and no parameters set.
+        assert_eq!(parse_priority_field("bogus"), (DEFAULT_URGENCY, false));
+        assert_eq!(parse_priority_field(""), (DEFAULT_URGENCY, false));
+    }
+}
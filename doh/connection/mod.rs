@@ -0,0 +1,44 @@
+/*
+* Copyright (C) 2021 The Android Open Source Project
+*
+* Licensed under the Apache License, Version 2.0 (the "License");
+* you may not use this file except in compliance with the License.
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+* Unless required by applicable law or agreed to in writing, software
+* distributed under the License is distributed on an "AS IS" BASIS,
+* WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+* See the License for the specific language governing permissions and
+* limitations under the License.
+*/
+
+//! A DoH connection's driving task and its observable status.
+
+mod driver;
+
+pub use driver::{
+    drive, Cause, EchMode, Error, HandshakeEch, HandshakeInfo, HandshakeResult, Request,
+    Result as DriverResult, Stream,
+};
+
+/// The status of a DoH connection, as observed by `Network`.
+#[derive(Debug)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum Status {
+    /// QUIC transport is up, but HTTP/3 isn't established yet.
+    QUIC,
+    /// HTTP/3 is up and accepting requests.
+    H3,
+    /// The connection has died. Carries the session ticket (if any) for a
+    /// future resumption attempt, every request that never got a final
+    /// answer so `Network` can re-dispatch them onto a new connection, and,
+    /// if the server rejected Encrypted Client Hello and supplied retry
+    /// configs, the updated config list to retry the connection with once
+    /// (via `Cause::Reconnect`).
+    Dead {
+        session: Option<Vec<u8>>,
+        pending: Vec<Request>,
+        ech_retry_config: Option<Vec<u8>>,
+    },
+}